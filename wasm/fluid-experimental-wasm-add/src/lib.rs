@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "fast-math", feature(core_intrinsics))]
+
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -5,6 +7,15 @@ pub fn add(left: f64, right: f64) -> f64{
     left + right
 }
 
+// Fast-math variant for hot numeric loops. `fadd_fast` assumes finite, non-NaN
+// operands and lets the backend reassociate/vectorize; it is nightly-only and
+// `unsafe`, so the strict `add` above stays the default on stable builds.
+#[cfg(feature = "fast-math")]
+#[wasm_bindgen]
+pub fn add_fast(left: f64, right: f64) -> f64 {
+    unsafe { core::intrinsics::fadd_fast(left, right) }
+}
+
 #[cfg(test)]
 pub mod test {
     use crate::add;
@@ -12,4 +23,13 @@ pub mod test {
     pub fn adds_correctly() {
         assert_eq!(add(1 as f64, 2 as f64), 3 as f64);
     }
+
+    #[cfg(feature = "fast-math")]
+    #[test]
+    pub fn fast_matches_strict() {
+        use crate::add_fast;
+        for &(left, right) in &[(1 as f64, 2 as f64), (-3.5, 4.25), (1e9, 1e-9)] {
+            assert_eq!(add_fast(left, right), add(left, right));
+        }
+    }
 }
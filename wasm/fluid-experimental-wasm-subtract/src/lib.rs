@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "fast-math", feature(core_intrinsics))]
+
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -5,6 +7,15 @@ pub fn subtract(left: f64, right: f64) -> f64 {
     left - right
 }
 
+// Fast-math variant for hot numeric loops. `fsub_fast` assumes finite, non-NaN
+// operands and lets the backend reassociate/vectorize; it is nightly-only and
+// `unsafe`, so the strict `subtract` above stays the default on stable builds.
+#[cfg(feature = "fast-math")]
+#[wasm_bindgen]
+pub fn subtract_fast(left: f64, right: f64) -> f64 {
+    unsafe { core::intrinsics::fsub_fast(left, right) }
+}
+
 #[cfg(test)]
 pub mod test {
     use crate::subtract;
@@ -12,4 +23,13 @@ pub mod test {
     pub fn subtracts_correctly() {
         assert_eq!(subtract(2 as f64, 1 as f64), 1 as f64);
     }
+
+    #[cfg(feature = "fast-math")]
+    #[test]
+    pub fn fast_matches_strict() {
+        use crate::subtract_fast;
+        for &(left, right) in &[(2 as f64, 1 as f64), (4.25, -3.5), (1e9, 1e-9)] {
+            assert_eq!(subtract_fast(left, right), subtract(left, right));
+        }
+    }
 }
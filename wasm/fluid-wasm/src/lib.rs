@@ -1,13 +1,291 @@
+#[cfg(any(feature = "bindgen", feature = "component", feature = "bench"))]
 use fluid_wasm_add::add as add_core;
+#[cfg(any(feature = "bindgen", feature = "component", feature = "bench"))]
 use fluid_wasm_subtract::subtract as subtract_core;
+
+#[cfg(feature = "bindgen")]
 use wasm_bindgen::prelude::*;
 
+#[cfg(feature = "bindgen")]
 #[wasm_bindgen]
 pub fn add(left: f64, right: f64) -> f64{
 	add_core(left, right)
 }
 
+#[cfg(feature = "bindgen")]
 #[wasm_bindgen]
 pub fn subtract(left: f64, right: f64) -> f64{
 	subtract_core(left, right)
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "bindgen")]
+#[wasm_bindgen]
+pub fn add_slice(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsError>{
+	if a.len() != b.len() {
+		return Err(JsError::new("add_slice: input slices differ in length"));
+	}
+	Ok(a.iter().zip(b).map(|(&l, &r)| add_core(l, r)).collect())
+}
+
+#[cfg(feature = "bindgen")]
+#[wasm_bindgen]
+pub fn subtract_slice(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsError>{
+	if a.len() != b.len() {
+		return Err(JsError::new("subtract_slice: input slices differ in length"));
+	}
+	Ok(a.iter().zip(b).map(|(&l, &r)| subtract_core(l, r)).collect())
+}
+
+#[cfg(feature = "bindgen")]
+#[wasm_bindgen]
+pub fn add_scalar(xs: &[f64], k: f64) -> Vec<f64>{
+	xs.iter().map(|&x| add_core(x, k)).collect()
+}
+
+#[cfg(all(test, feature = "bindgen"))]
+pub mod test {
+	use crate::{add_scalar, add_slice, subtract_slice};
+
+	#[test]
+	pub fn add_slice_adds_elementwise() {
+		assert_eq!(add_slice(&[1.0, 2.0], &[3.0, 4.0]).unwrap(), vec![4.0, 6.0]);
+	}
+
+	#[test]
+	pub fn subtract_slice_subtracts_elementwise() {
+		assert_eq!(subtract_slice(&[3.0, 4.0], &[1.0, 2.0]).unwrap(), vec![2.0, 2.0]);
+	}
+
+	#[test]
+	pub fn add_scalar_broadcasts() {
+		assert_eq!(add_scalar(&[1.0, 2.0, 3.0], 10.0), vec![11.0, 12.0, 13.0]);
+	}
+
+	#[test]
+	pub fn slice_length_mismatch_is_err() {
+		assert!(add_slice(&[1.0, 2.0], &[3.0]).is_err());
+		assert!(subtract_slice(&[1.0], &[3.0, 4.0]).is_err());
+	}
+}
+
+// Internal microbenchmark subsystem. Times the core and batch ops with a
+// high-resolution clock so the cost of each boundary call can be quantified.
+// The clock is abstracted behind a trait with a `performance.now()`-backed
+// implementation for the browser and a `quanta::Clock` one for native test
+// builds, keeping the measurement loop identical across both.
+#[cfg(feature = "bench")]
+mod bench {
+	use super::{add_core, subtract_core};
+	use core::hint::black_box;
+	use serde::Serialize;
+	use wasm_bindgen::prelude::*;
+
+	/// Monotonic, high-resolution clock reporting nanoseconds since an arbitrary
+	/// origin. The measurement loop only takes deltas, so the origin is irrelevant.
+	trait Clock {
+		fn now_nanos(&self) -> f64;
+	}
+
+	#[cfg(target_arch = "wasm32")]
+	struct PerformanceClock {
+		performance: web_sys::Performance,
+	}
+
+	#[cfg(target_arch = "wasm32")]
+	impl PerformanceClock {
+		fn new() -> Self {
+			let performance = web_sys::window()
+				.expect("no global `window` exists")
+				.performance()
+				.expect("`performance` is unavailable");
+			Self { performance }
+		}
+	}
+
+	#[cfg(target_arch = "wasm32")]
+	impl Clock for PerformanceClock {
+		fn now_nanos(&self) -> f64 {
+			// `performance.now()` yields fractional milliseconds.
+			self.performance.now() * 1.0e6
+		}
+	}
+
+	#[cfg(not(target_arch = "wasm32"))]
+	struct QuantaClock {
+		clock: quanta::Clock,
+		origin: u64,
+	}
+
+	#[cfg(not(target_arch = "wasm32"))]
+	impl QuantaClock {
+		fn new() -> Self {
+			// Calibrates the TSC, falling back to a monotonic source when absent.
+			let clock = quanta::Clock::new();
+			let origin = clock.raw();
+			Self { clock, origin }
+		}
+	}
+
+	#[cfg(not(target_arch = "wasm32"))]
+	impl Clock for QuantaClock {
+		fn now_nanos(&self) -> f64 {
+			self.clock.delta_as_nanos(self.origin, self.clock.raw()) as f64
+		}
+	}
+
+	/// Per-element latency summary for a single op, in nanoseconds.
+	#[derive(Serialize)]
+	struct OpReport {
+		name: &'static str,
+		min_ns: f64,
+		median_ns: f64,
+		p99_ns: f64,
+	}
+
+	/// Full report returned to JS as a JSON-serializable value.
+	#[derive(Serialize)]
+	struct BenchmarkReport {
+		iters: u32,
+		elements: usize,
+		ops: Vec<OpReport>,
+	}
+
+	fn percentile(sorted: &[f64], q: f64) -> f64 {
+		if sorted.is_empty() {
+			return 0.0;
+		}
+		let rank = (q * (sorted.len() - 1) as f64).round() as usize;
+		sorted[rank]
+	}
+
+	/// Runs `op` over the whole buffer `iters` times and summarises the per-element
+	/// latency. `op` processes `elements` items per call.
+	fn measure<C, F>(clock: &C, name: &'static str, iters: u32, elements: usize, mut op: F) -> OpReport
+	where
+		C: Clock,
+		F: FnMut(),
+	{
+		let mut per_element = Vec::with_capacity(iters as usize);
+		for _ in 0..iters {
+			let start = clock.now_nanos();
+			op();
+			let end = clock.now_nanos();
+			per_element.push((end - start) / elements as f64);
+		}
+		per_element.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+		OpReport {
+			name,
+			min_ns: per_element.first().copied().unwrap_or(0.0),
+			median_ns: percentile(&per_element, 0.50),
+			p99_ns: percentile(&per_element, 0.99),
+		}
+	}
+
+	fn run<C: Clock>(clock: &C, iters: u32) -> BenchmarkReport {
+		let elements = 1024usize;
+		let a: Vec<f64> = (0..elements).map(|i| i as f64).collect();
+		let b: Vec<f64> = (0..elements).map(|i| (elements - i) as f64).collect();
+
+		let mut ops = Vec::new();
+
+		ops.push(measure(clock, "add_core", iters, elements, || {
+			for (&l, &r) in a.iter().zip(&b) {
+				black_box(add_core(black_box(l), black_box(r)));
+			}
+		}));
+		ops.push(measure(clock, "subtract_core", iters, elements, || {
+			for (&l, &r) in a.iter().zip(&b) {
+				black_box(subtract_core(black_box(l), black_box(r)));
+			}
+		}));
+		ops.push(measure(clock, "add_slice", iters, elements, || {
+			let out: Vec<f64> = a.iter().zip(&b).map(|(&l, &r)| add_core(l, r)).collect();
+			black_box(out);
+		}));
+		ops.push(measure(clock, "subtract_slice", iters, elements, || {
+			let out: Vec<f64> = a.iter().zip(&b).map(|(&l, &r)| subtract_core(l, r)).collect();
+			black_box(out);
+		}));
+
+		#[cfg(feature = "fast-math")]
+		{
+			use fluid_wasm_add::add_fast;
+			use fluid_wasm_subtract::subtract_fast;
+			ops.push(measure(clock, "add_fast", iters, elements, || {
+				for (&l, &r) in a.iter().zip(&b) {
+					black_box(add_fast(black_box(l), black_box(r)));
+				}
+			}));
+			ops.push(measure(clock, "subtract_fast", iters, elements, || {
+				for (&l, &r) in a.iter().zip(&b) {
+					black_box(subtract_fast(black_box(l), black_box(r)));
+				}
+			}));
+		}
+
+		BenchmarkReport { iters, elements, ops }
+	}
+
+	/// Runs the benchmark suite and returns a JSON-serializable report. A
+	/// serialization failure is surfaced to JS as an exception rather than a
+	/// silent null report.
+	#[wasm_bindgen]
+	pub fn run_benchmarks(iters: u32) -> Result<JsValue, JsError> {
+		#[cfg(target_arch = "wasm32")]
+		let clock = PerformanceClock::new();
+		#[cfg(not(target_arch = "wasm32"))]
+		let clock = QuantaClock::new();
+
+		let report = run(&clock, iters);
+		serde_wasm_bindgen::to_value(&report).map_err(|e| JsError::new(&e.to_string()))
+	}
+
+	#[cfg(all(test, not(target_arch = "wasm32")))]
+	mod test {
+		use super::{run, QuantaClock};
+
+		#[test]
+		fn run_collects_a_report_for_each_op() {
+			let clock = QuantaClock::new();
+			let report = run(&clock, 4);
+			assert_eq!(report.iters, 4);
+			assert_eq!(report.elements, 1024);
+			// `add_core`, `subtract_core`, `add_slice`, `subtract_slice` always run;
+			// the two fast-math ops are added when that feature is enabled.
+			let expected = if cfg!(feature = "fast-math") { 6 } else { 4 };
+			assert_eq!(report.ops.len(), expected);
+			assert!(report.ops.iter().any(|op| op.name == "add_core"));
+			for op in &report.ops {
+				assert!(op.min_ns <= op.median_ns);
+				assert!(op.median_ns <= op.p99_ns);
+			}
+		}
+	}
+}
+
+// Component Model (WASI Preview 2) export path. The arithmetic surface lives in
+// `wit/math.wit`; `wit-bindgen` generates the `Guest` trait we implement here by
+// delegating to the same core ops the `bindgen` path uses.
+#[cfg(feature = "component")]
+mod component {
+	use super::{add_core, subtract_core};
+
+	wit_bindgen::generate!({
+		world: "math",
+		path: "wit",
+	});
+
+	struct Math;
+
+	impl exports::fluid::math::math::Guest for Math {
+		fn add(a: f64, b: f64) -> f64 {
+			add_core(a, b)
+		}
+
+		fn subtract(a: f64, b: f64) -> f64 {
+			subtract_core(a, b)
+		}
+	}
+
+	export!(Math);
+}
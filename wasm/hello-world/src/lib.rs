@@ -5,11 +5,44 @@ pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
 
+// Overflow-aware addition. The bare `add` above wraps silently on overflow in
+// release builds (and panics in builds with overflow checks, such as debug and
+// test builds), which corrupts sums of counts coming from Fluid's data structures. `checked_add`
+// returns both the wrapped value and an explicit flag; wasm-bindgen marshals the
+// struct fields back to JS by value.
+#[wasm_bindgen]
+pub struct AddResult {
+    pub value: usize,
+    pub overflowed: bool,
+}
+
+#[wasm_bindgen]
+pub fn checked_add(left: usize, right: usize) -> AddResult {
+    AddResult {
+        value: left.wrapping_add(right),
+        overflowed: left.checked_add(right).is_none(),
+    }
+}
+
 #[cfg(test)]
 pub mod test {
-    use crate::add;
+    use crate::{add, checked_add};
     #[test]
     pub fn adds_correctly() {
         assert_eq!(add(1, 2), 3);
     }
+
+    #[test]
+    pub fn checked_add_reports_no_overflow() {
+        let result = checked_add(1, 2);
+        assert_eq!(result.value, 3);
+        assert!(!result.overflowed);
+    }
+
+    #[test]
+    pub fn checked_add_flags_boundary_overflow() {
+        let result = checked_add(usize::MAX, 1);
+        assert_eq!(result.value, 0);
+        assert!(result.overflowed);
+    }
 }